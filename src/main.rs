@@ -17,9 +17,9 @@ const ERROR_ACCESS_DENIED: i32 = 5;
 
 fn print_usage() {
     #[cfg(not(windows))]
-    println!("USAGE: hddrand [--verify] /dev/disk");
+    println!("USAGE: hddrand [--verify] [--threads N] /dev/disk");
     #[cfg(windows)]
-    println!("USAGE: hddrand [--verify] \\??\\PhysicalDrive8");
+    println!("USAGE: hddrand [--verify] [--threads N] \\??\\PhysicalDrive8");
 }
 
 fn main() {
@@ -31,9 +31,27 @@ fn main() {
 
     let mut drive = None;
     let mut verify = false;
-    for arg in args.iter() {
+    let mut threads: usize = 1;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
         match arg.as_str() {
             "verify" | "--verify" => verify = true,
+            "--threads" => {
+                let value = match args_iter.next() {
+                    Some(value) => value,
+                    None => {
+                        eprintln!("--threads requires a value");
+                        std::process::exit(1);
+                    }
+                };
+                threads = match value.parse() {
+                    Ok(threads) if threads > 0 => threads,
+                    _ => {
+                        eprintln!("--threads requires a positive integer");
+                        std::process::exit(1);
+                    }
+                };
+            }
             path => {
                 if path.starts_with('/') || path.starts_with('\\') {
                     drive = Some(path);
@@ -76,9 +94,9 @@ fn main() {
     }
 
     let result = if verify {
-        verify_drive(path)
+        verify_drive(path, threads)
     } else {
-        fill_drive(path)
+        fill_drive(path, threads)
     };
 
     eprintln!("\n");
@@ -111,7 +129,106 @@ where
     }
 }
 
-fn verify_drive(path: &Path) -> std::io::Result<(usize, Duration)> {
+// ChaCha is a seekable stream cipher: rand_chacha measures position in 32-bit words, 16 words
+// (64 bytes) per block. Keeping region boundaries aligned to 64 bytes means `set_word_pos` always
+// lands exactly on a block boundary instead of requiring us to discard partial-block output.
+const REGION_ALIGNMENT: u64 = 64;
+
+// Splits `total_size` bytes into `threads` contiguous, 64-byte-aligned regions and returns each
+// region's (start, len). The last region absorbs whatever the alignment rounding leaves over.
+// `threads` is capped so that every region is at least one alignment unit: region 0 carries the
+// seed, and a zero-length region 0 would silently skip writing/reading it.
+fn partition_regions(total_size: u64, threads: usize) -> Vec<(u64, u64)> {
+    let max_regions = (total_size / REGION_ALIGNMENT).max(1);
+    let threads = (threads as u64).clamp(1, max_regions);
+    let region_len = (total_size / threads) / REGION_ALIGNMENT * REGION_ALIGNMENT;
+
+    let mut regions = Vec::new();
+    let mut offset = 0;
+    for i in 0..threads {
+        let len = if i + 1 == threads {
+            total_size - offset
+        } else {
+            region_len
+        };
+        regions.push((offset, len));
+        offset += len;
+    }
+    regions
+}
+
+fn drive_size(path: &Path) -> std::io::Result<u64> {
+    let mut file = OpenOptions::new().read(true).open(path)?;
+    file.seek(SeekFrom::End(0))
+}
+
+fn read_seed(path: &Path) -> std::io::Result<[u8; 32]> {
+    let mut file = OpenOptions::new().read(true).open(path)?;
+
+    // This needs to be a multiple of the page size on some platforms!
+    let mut seed_buf = [0u8; 1024];
+    let mut bytes_read = 0;
+    while bytes_read < 32 {
+        let read = file.read(&mut seed_buf)?;
+        bytes_read += read;
+        if read == 0 {
+            panic!("Unable to read the seed out of the source!");
+        }
+    }
+
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&seed_buf[0..32]);
+    Ok(seed)
+}
+
+fn verify_drive(path: &Path, threads: usize) -> std::io::Result<(usize, Duration)> {
+    // Single-threaded verification doesn't need to know the total size up front, so it keeps
+    // streaming until EOF instead of requiring a seekable-to-end target.
+    if threads <= 1 {
+        return verify_drive_single(path);
+    }
+
+    let seed = read_seed(path)?;
+    let total_size = drive_size(path)?;
+    let regions = partition_regions(total_size, threads);
+
+    let start = Instant::now();
+    let done = Arc::new(AtomicBool::new(false));
+    let total_read = Arc::new(AtomicUsize::new(0));
+    let abort = Arc::new(AtomicBool::new(false));
+
+    start_progress_thread(total_read.clone(), done.clone());
+    let _on_drop = OnDrop(|| done.clone().store(true, Ordering::Release));
+
+    let workers: Vec<_> = regions
+        .into_iter()
+        .enumerate()
+        .map(|(index, (region_start, region_len))| {
+            let path = path.to_path_buf();
+            let total_read = total_read.clone();
+            let abort = abort.clone();
+            std::thread::spawn(move || {
+                verify_region(
+                    &path,
+                    seed,
+                    region_start,
+                    region_len,
+                    index == 0,
+                    &total_read,
+                    &abort,
+                )
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().expect("verify worker thread panicked")?;
+    }
+
+    Ok((total_read.load(Ordering::Acquire), start.elapsed()))
+}
+
+fn verify_drive_single(path: &Path) -> std::io::Result<(usize, Duration)> {
     let mut first_time = true;
     let mut read_buffer = Vec::new();
     read_buffer.resize(8 * 1024 * 1024, 0u8);
@@ -190,7 +307,126 @@ fn verify_drive(path: &Path) -> std::io::Result<(usize, Duration)> {
     }
 }
 
-fn fill_drive(path: &Path) -> std::io::Result<(usize, Duration)> {
+// Verifies a single region. Region 0 holds the seed in its first 32 bytes (written in place of
+// keystream output by `fill_region`), so it must reproduce that same overwrite before comparing.
+fn verify_region(
+    path: &Path,
+    seed: [u8; 32],
+    region_start: u64,
+    region_len: u64,
+    is_first_region: bool,
+    total_read: &AtomicUsize,
+    abort: &AtomicBool,
+) -> std::io::Result<()> {
+    let mut read_buffer = Vec::new();
+    read_buffer.resize(8 * 1024 * 1024, 0u8);
+    let mut rand_buffer = Vec::new();
+    rand_buffer.resize(8 * 1024 * 1024, 0u8);
+
+    let mut file = OpenOptions::new().read(true).open(path)?;
+    file.seek(SeekFrom::Start(region_start))?;
+
+    let mut rng = rand_chacha::ChaCha8Rng::from_seed(seed);
+    rng.set_word_pos((region_start / 4) as u128);
+
+    let mut first_time = is_first_region;
+    let mut region_read = 0u64;
+    while region_read < region_len && !abort.load(Ordering::Acquire) {
+        let chunk_len = read_buffer.len().min((region_len - region_read) as usize);
+        rng.fill_bytes(&mut rand_buffer[..chunk_len]);
+        if first_time {
+            (&mut rand_buffer[..chunk_len]).write_all(&seed[..])?;
+            first_time = false;
+        }
+
+        let mut write_offset = 0;
+        loop {
+            let read = file.read(&mut read_buffer[write_offset..chunk_len])?;
+
+            if read_buffer[write_offset..][..read] != rand_buffer[write_offset..][..read] {
+                // Mismatch in expected contents! Signal the other workers so they stop early too.
+                abort.store(true, Ordering::Release);
+
+                // Find the start of the mismatch
+                let mut mismatch_start = 0;
+                for i in write_offset..(write_offset + read) {
+                    if read_buffer[i] != rand_buffer[i] {
+                        mismatch_start = i;
+                        break;
+                    }
+                }
+                eprintln!(
+                    "Content mismatch starting at offset {:x}",
+                    region_start + (region_read - write_offset as u64) + mismatch_start as u64
+                );
+                eprintln!(
+                    "Expected {:x}, found {:x}",
+                    rand_buffer[mismatch_start], read_buffer[mismatch_start]
+                );
+                return Ok(());
+            }
+
+            write_offset += read;
+            region_read += read as u64;
+            total_read.fetch_add(read, Ordering::SeqCst);
+
+            if write_offset == chunk_len {
+                break;
+            }
+            if read == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn fill_drive(path: &Path, threads: usize) -> std::io::Result<(usize, Duration)> {
+    // Single-threaded filling doesn't need to know the total size up front, so it keeps streaming
+    // until ENOSPC instead of requiring a seekable-to-end target.
+    if threads <= 1 {
+        return fill_drive_single(path);
+    }
+
+    let seed: [u8; 32] = rand::random();
+    let total_size = drive_size(path)?;
+    let regions = partition_regions(total_size, threads);
+
+    let start = Instant::now();
+    let done = Arc::new(AtomicBool::new(false));
+    let total_written = Arc::new(AtomicUsize::new(0));
+
+    start_progress_thread(total_written.clone(), done.clone());
+    let _on_drop = OnDrop(|| done.clone().store(true, Ordering::Release));
+
+    let workers: Vec<_> = regions
+        .into_iter()
+        .enumerate()
+        .map(|(index, (region_start, region_len))| {
+            let path = path.to_path_buf();
+            let total_written = total_written.clone();
+            std::thread::spawn(move || {
+                fill_region(
+                    &path,
+                    seed,
+                    region_start,
+                    region_len,
+                    index == 0,
+                    &total_written,
+                )
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().expect("fill worker thread panicked")?;
+    }
+
+    Ok((total_written.load(Ordering::Acquire), start.elapsed()))
+}
+
+fn fill_drive_single(path: &Path) -> std::io::Result<(usize, Duration)> {
     let seed: [u8; 32] = rand::random();
     let mut rng = rand_chacha::ChaCha8Rng::from_seed(seed);
 
@@ -235,6 +471,58 @@ fn fill_drive(path: &Path) -> std::io::Result<(usize, Duration)> {
     }
 }
 
+// Fills a single region with the keystream seeked to its starting offset. Region 0 additionally
+// overwrites its first 32 bytes with the seed itself, exactly as the single-threaded loop did.
+fn fill_region(
+    path: &Path,
+    seed: [u8; 32],
+    region_start: u64,
+    region_len: u64,
+    is_first_region: bool,
+    total_written: &AtomicUsize,
+) -> std::io::Result<()> {
+    let mut buffer = Vec::new();
+    buffer.resize(8 * 1024 * 1024, 0u8);
+
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.seek(SeekFrom::Start(region_start))?;
+
+    let mut rng = rand_chacha::ChaCha8Rng::from_seed(seed);
+    rng.set_word_pos((region_start / 4) as u128);
+
+    let mut first_time = is_first_region;
+    let mut region_written = 0u64;
+    while region_written < region_len {
+        let chunk_len = buffer.len().min((region_len - region_written) as usize);
+        rng.fill_bytes(&mut buffer[..chunk_len]);
+        if first_time {
+            (&mut buffer[..chunk_len]).write_all(&seed)?;
+            first_time = false;
+        }
+
+        let mut write_offset = 0;
+        loop {
+            let written = match file.write(&buffer[write_offset..chunk_len]) {
+                Ok(bytes) => bytes,
+                Err(e) if e.raw_os_error() == Some(ENOSPC) => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            write_offset += written;
+            region_written += written as u64;
+            total_written.fetch_add(written, Ordering::SeqCst);
+
+            if write_offset == chunk_len {
+                break;
+            }
+            if written == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn start_progress_thread(total_bytes: Arc<AtomicUsize>, done: Arc<AtomicBool>) {
     std::thread::spawn(move || {
         let mut timer = Instant::now();